@@ -1,27 +1,36 @@
-use std::{fmt::Display, net::Ipv4Addr};
+use std::{
+    fmt::Display,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
 
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::Error,
     helpers::{
-        get_broadcast_addr, get_first_host_addr, get_host_values, get_last_host_addr,
-        get_network_addr, get_subnet_mask, get_wildcard_mask,
+        addr_to_u128, get_broadcast_addr, get_first_host_addr, get_host_values,
+        get_last_host_addr, get_network_addr, get_subnet_mask, get_wildcard_mask, max_prefix,
+        u128_to_addr,
     },
+    ip_class::{classify, scope, IpClass, Scope},
 };
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct CidrInfo {
-    pub ip: Ipv4Addr,
+    pub ip: IpAddr,
     pub cidr: u8,
-    pub subnet_mask: Ipv4Addr,
-    pub wildcard_mask: Ipv4Addr,
-    pub first_host_addr: Ipv4Addr,
-    pub last_host_addr: Ipv4Addr,
-    pub usable_hosts: u64,
-    pub network_addr: Ipv4Addr,
-    pub broadcast_addr: Ipv4Addr,
-    pub total_hosts: u64,
+    pub subnet_mask: IpAddr,
+    pub wildcard_mask: IpAddr,
+    pub first_host_addr: IpAddr,
+    pub last_host_addr: IpAddr,
+    pub usable_hosts: u128,
+    pub network_addr: IpAddr,
+    pub broadcast_addr: IpAddr,
+    pub total_hosts: u128,
+    /// Classful IPv4 class (A-E). `None` for IPv6, which has no classful addressing.
+    pub class: Option<IpClass>,
+    /// Special-purpose ranges this block's address falls into. `None` for IPv6.
+    pub scope: Option<Scope>,
 }
 
 impl Display for CidrInfo {
@@ -34,11 +43,13 @@ cidr.............: {cidr}
 subnet_mask......: {subnet_mask}
 wildcard_mask....: {wildcard_mask}
 first_host_addr..: {first_host_addr}
-last_host_addr...: {last_host_addr} 
+last_host_addr...: {last_host_addr}
 usable_hosts.....: {usable_hosts}
 network_addr.....: {network_addr}
 broadcast_addr...: {broadcast_addr}
-total_hosts......: {total_hosts}",
+total_hosts......: {total_hosts}
+class............: {class}
+publicly_routable: {publicly_routable}",
             ip = self.ip,
             cidr = self.cidr,
             subnet_mask = self.subnet_mask,
@@ -49,21 +60,54 @@ total_hosts......: {total_hosts}",
             network_addr = self.network_addr,
             broadcast_addr = self.broadcast_addr,
             total_hosts = self.total_hosts,
+            class = self
+                .class
+                .as_ref()
+                .map_or_else(|| "n/a".to_string(), IpClass::to_string),
+            publicly_routable = self
+                .scope
+                .as_ref()
+                .map_or_else(|| "n/a".to_string(), |s| s.is_publicly_routable().to_string()),
         )
     }
 }
 
 impl CidrInfo {
-    pub fn new(ip: Ipv4Addr, cidr: u8) -> Result<Self, Error> {
-        let (hosts_total, hosts_usable) = get_host_values(cidr);
+    pub fn new(ip: IpAddr, cidr: u8) -> Result<Self, Error> {
+        Self::build(ip, cidr, false)
+    }
+
+    /// Like [`CidrInfo::new`], but rejects `ip` if it has any host bits set, i.e.
+    /// requires `ip` to already be the network address of the block.
+    pub fn new_strict(ip: IpAddr, cidr: u8) -> Result<Self, Error> {
+        Self::build(ip, cidr, true)
+    }
+
+    fn build(ip: IpAddr, cidr: u8, strict: bool) -> Result<Self, Error> {
+        let max_cidr = max_prefix(ip);
+        if cidr > max_cidr {
+            return Err(Error::CidrOutOfRange(cidr, max_cidr));
+        }
+
+        let (hosts_total, hosts_usable) = get_host_values(ip, cidr);
 
-        let mask_subnet = get_subnet_mask(cidr);
+        let mask_subnet = get_subnet_mask(ip, cidr);
         let mask_wildcard = get_wildcard_mask(mask_subnet);
         let addr_network = get_network_addr(mask_subnet, ip);
+
+        if strict && addr_network != ip {
+            return Err(Error::HostBitsTooLarge);
+        }
+
         let addr_host_first = get_first_host_addr(addr_network, hosts_usable);
         let addr_broadcast = get_broadcast_addr(mask_wildcard, ip);
         let addr_host_last = get_last_host_addr(addr_broadcast, hosts_usable);
 
+        let (class, scope) = match ip {
+            IpAddr::V4(v4) => (Some(classify(v4)), Some(scope(v4))),
+            IpAddr::V6(_) => (None, None),
+        };
+
         Ok(CidrInfo {
             ip,
             cidr,
@@ -75,36 +119,345 @@ impl CidrInfo {
             broadcast_addr: addr_broadcast,
             usable_hosts: hosts_usable,
             total_hosts: hosts_total,
+            class,
+            scope,
+        })
+    }
+
+    /// Returns `true` if `ip` falls within this block's network and broadcast
+    /// addresses (inclusive). Always `false` if `ip` is a different address
+    /// family than this block.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        if ip.is_ipv4() != self.ip.is_ipv4() {
+            return false;
+        }
+
+        addr_to_u128(self.network_addr) <= addr_to_u128(ip)
+            && addr_to_u128(ip) <= addr_to_u128(self.broadcast_addr)
+    }
+
+    /// Returns every usable host address in this block, from `first_host_addr` to
+    /// `last_host_addr` inclusive. Empty when `usable_hosts` is 0 (e.g. a `/31`
+    /// or `/32`).
+    pub fn hosts(&self) -> Hosts {
+        if self.usable_hosts == 0 {
+            return Hosts {
+                is_v4: self.ip.is_ipv4(),
+                next: 1,
+                last: 0,
+                exhausted: true,
+            };
+        }
+
+        Hosts {
+            is_v4: self.ip.is_ipv4(),
+            next: addr_to_u128(self.first_host_addr),
+            last: addr_to_u128(self.last_host_addr),
+            exhausted: false,
+        }
+    }
+
+    /// Returns `true` if `self` and `other` share any address, i.e. their
+    /// `[network_addr, broadcast_addr]` ranges intersect. Always `false` across
+    /// address families.
+    pub fn overlaps(&self, other: &CidrInfo) -> bool {
+        if self.ip.is_ipv4() != other.ip.is_ipv4() {
+            return false;
+        }
+
+        addr_to_u128(self.network_addr) <= addr_to_u128(other.broadcast_addr)
+            && addr_to_u128(other.network_addr) <= addr_to_u128(self.broadcast_addr)
+    }
+
+    /// Returns `true` if this block is entirely contained within `other`, i.e.
+    /// `other`'s prefix is no longer than this one and this block's range falls
+    /// inside `other`'s. Always `false` across address families.
+    pub fn is_subnet_of(&self, other: &CidrInfo) -> bool {
+        if self.ip.is_ipv4() != other.ip.is_ipv4() {
+            return false;
+        }
+
+        self.cidr >= other.cidr
+            && addr_to_u128(other.network_addr) <= addr_to_u128(self.network_addr)
+            && addr_to_u128(self.broadcast_addr) <= addr_to_u128(other.broadcast_addr)
+    }
+
+    /// Returns `true` if `other` is entirely contained within this block. The
+    /// inverse of [`CidrInfo::is_subnet_of`].
+    pub fn is_supernet_of(&self, other: &CidrInfo) -> bool {
+        other.is_subnet_of(self)
+    }
+
+    /// Returns every address in this block, from `network_addr` to `broadcast_addr`
+    /// inclusive (unlike [`CidrInfo::hosts`], this includes the network and
+    /// broadcast addresses themselves).
+    pub fn addresses(&self) -> Hosts {
+        Hosts {
+            is_v4: self.ip.is_ipv4(),
+            next: addr_to_u128(self.network_addr),
+            last: addr_to_u128(self.broadcast_addr),
+            exhausted: false,
+        }
+    }
+
+    /// Divides this block into every contained subnet of prefix length `new_cidr`.
+    pub fn split_into(&self, new_cidr: u8) -> Result<Vec<CidrInfo>, Error> {
+        let max_cidr = max_prefix(self.ip);
+        if new_cidr > max_cidr {
+            return Err(Error::CidrOutOfRange(new_cidr, max_cidr));
+        }
+        if new_cidr <= self.cidr {
+            return Err(Error::SplitPrefixTooSmall(new_cidr, self.cidr));
+        }
+
+        let child_count = 1u128.checked_shl((new_cidr - self.cidr) as u32).unwrap_or(u128::MAX);
+        if child_count > MAX_SPLIT_CHILDREN {
+            return Err(Error::SplitTooLarge(
+                new_cidr,
+                self.cidr,
+                child_count,
+                MAX_SPLIT_CHILDREN,
+            ));
+        }
+        let block_size = 1u128.checked_shl((max_cidr - new_cidr) as u32).unwrap_or(u128::MAX);
+        let network = addr_to_u128(self.network_addr);
+
+        (0..child_count)
+            .map(|i| {
+                let child_ip = u128_to_addr(network + i * block_size, self.network_addr);
+                CidrInfo::new(child_ip, new_cidr)
+            })
+            .collect()
+    }
+
+    /// Splits this block into child subnets sized to satisfy `host_counts`, one
+    /// child per requested count. Requests are packed largest-first so each
+    /// child lands on the smallest subnet that fits it, aligned to its own
+    /// size; results are returned in the same order as `host_counts`. Errors
+    /// if the parent block is too small to fit every request.
+    pub fn split_by_hosts(&self, host_counts: &[u128]) -> Result<Vec<CidrInfo>, Error> {
+        let width = max_prefix(self.ip);
+        let network = addr_to_u128(self.network_addr);
+        let broadcast = addr_to_u128(self.broadcast_addr);
+
+        let mut order: Vec<usize> = (0..host_counts.len()).collect();
+        order.sort_by(|&a, &b| host_counts[b].cmp(&host_counts[a]));
+
+        let mut children: Vec<Option<CidrInfo>> = (0..host_counts.len()).map(|_| None).collect();
+        let mut cursor = network;
+
+        for index in order {
+            let size = block_size_for_hosts(host_counts[index], width);
+
+            let aligned = match cursor.checked_add(size - 1) {
+                Some(ceiling) => (ceiling / size) * size,
+                None => return Err(Error::SplitExhausted),
+            };
+            let last = aligned.checked_add(size - 1).ok_or(Error::SplitExhausted)?;
+            if last > broadcast {
+                return Err(Error::SplitExhausted);
+            }
+
+            let prefix = width - size.trailing_zeros() as u8;
+            let child_ip = u128_to_addr(aligned, self.network_addr);
+            children[index] = Some(CidrInfo::new(child_ip, prefix)?);
+            cursor = aligned + size;
+        }
+
+        Ok(children
+            .into_iter()
+            .map(|child| child.expect("every index is assigned exactly once"))
+            .collect())
+    }
+
+    /// Parses the compact 5-byte wire form: 4 IPv4 address octets followed by
+    /// the prefix length.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 5 {
+            return Err(Error::InvalidSize(bytes.len()));
+        }
+
+        let ip = Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]);
+        CidrInfo::new(IpAddr::V4(ip), bytes[4])
+    }
+
+    /// Encodes this block's address and prefix into the compact 5-byte wire
+    /// form (4 octets + prefix length). Errors for IPv6 blocks, which this
+    /// form cannot represent.
+    pub fn to_bytes(&self) -> Result<[u8; 5], Error> {
+        match self.ip {
+            IpAddr::V4(v4) => {
+                let octets = v4.octets();
+                Ok([octets[0], octets[1], octets[2], octets[3], self.cidr])
+            }
+            IpAddr::V6(_) => Err(Error::NotIpv4),
+        }
+    }
+
+    /// Collapses `blocks` into the minimal set of blocks covering the same
+    /// addresses ("route summarization"): merge overlapping/adjacent ranges,
+    /// then decompose each merged range back into aligned CIDR blocks. IPv4 and
+    /// IPv6 inputs are aggregated independently; order of the input is not
+    /// preserved.
+    pub fn aggregate(blocks: &[CidrInfo]) -> Vec<CidrInfo> {
+        let mut result = aggregate_family(blocks, IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        result.extend(aggregate_family(blocks, IpAddr::V6(Ipv6Addr::UNSPECIFIED)));
+        result
+    }
+}
+
+/// Ceiling on how many child subnets [`CidrInfo::split_into`] will materialize
+/// in one call, guarding against e.g. splitting a `/0` into `/32`s and trying
+/// to collect billions of `CidrInfo` values before printing anything.
+const MAX_SPLIT_CHILDREN: u128 = 1 << 20;
+
+/// The smallest power-of-two block size (in addresses) that can hold `hosts`
+/// usable hosts plus a network and broadcast address, capped at the full
+/// address space for the family.
+fn block_size_for_hosts(hosts: u128, width: u8) -> u128 {
+    let needed = hosts.saturating_add(2).max(1);
+    let max_size = 1u128.checked_shl(width as u32).unwrap_or(u128::MAX);
+
+    let mut size: u128 = 1;
+    while size < needed && size < max_size {
+        size <<= 1;
+    }
+    size.min(max_size)
+}
+
+fn aggregate_family(blocks: &[CidrInfo], family: IpAddr) -> Vec<CidrInfo> {
+    let width = max_prefix(family);
+
+    let mut ranges: Vec<(u128, u128)> = blocks
+        .iter()
+        .filter(|b| b.ip.is_ipv4() == family.is_ipv4())
+        .map(|b| (addr_to_u128(b.network_addr), addr_to_u128(b.broadcast_addr)))
+        .collect();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u128, u128)> = Vec::new();
+    for (lo, hi) in ranges {
+        match merged.last_mut() {
+            Some((_, prev_hi)) if lo <= prev_hi.checked_add(1).unwrap_or(u128::MAX) => {
+                *prev_hi = (*prev_hi).max(hi);
+            }
+            _ => merged.push((lo, hi)),
+        }
+    }
+
+    merged
+        .into_iter()
+        .flat_map(|(lo, hi)| decompose_range(lo, hi, width, family))
+        .collect()
+}
+
+/// Decomposes `[lo, hi]` into the fewest CIDR blocks aligned to their own size:
+/// at each step the next block's prefix is the shortest one that is both
+/// aligned to `lo` (bounded by its trailing zero bits) and does not overshoot
+/// `hi` (bounded by the remaining span).
+fn decompose_range(mut lo: u128, hi: u128, width: u8, family: IpAddr) -> Vec<CidrInfo> {
+    let mut blocks = Vec::new();
+
+    loop {
+        let align_bits = if lo == 0 {
+            width as u32
+        } else {
+            lo.trailing_zeros().min(width as u32)
+        };
+        let span_bits = if lo == 0 && hi == u128::MAX {
+            width as u32
+        } else {
+            (hi - lo + 1).ilog2().min(width as u32)
+        };
+        let block_bits = align_bits.min(span_bits);
+        let prefix = width - block_bits as u8;
+
+        blocks.push(
+            CidrInfo::new(u128_to_addr(lo, family), prefix)
+                .expect("aggregate always computes an aligned, in-range block"),
+        );
+
+        if block_bits as u8 == width {
+            break;
+        }
+
+        let block_size = 1u128 << block_bits;
+        match lo.checked_add(block_size) {
+            Some(next) if next <= hi => lo = next,
+            _ => break,
+        }
+    }
+
+    blocks
+}
+
+/// Iterator over a range of addresses of a [`CidrInfo`], yielded by [`CidrInfo::hosts`]
+/// (usable hosts only) and [`CidrInfo::addresses`] (every address).
+pub struct Hosts {
+    is_v4: bool,
+    next: u128,
+    last: u128,
+    exhausted: bool,
+}
+
+impl Iterator for Hosts {
+    type Item = IpAddr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted || self.next > self.last {
+            return None;
+        }
+
+        let value = self.next;
+        if value == self.last {
+            self.exhausted = true;
+        } else {
+            self.next += 1;
+        }
+
+        Some(if self.is_v4 {
+            IpAddr::V4(Ipv4Addr::from(value as u32))
+        } else {
+            IpAddr::V6(Ipv6Addr::from(value))
         })
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::{net::Ipv4Addr, str::FromStr};
+    use std::{
+        net::{IpAddr, Ipv4Addr, Ipv6Addr},
+        str::FromStr,
+    };
 
     use pretty_assertions::assert_eq;
 
-    use crate::cidr_info::CidrInfo;
+    use crate::{
+        cidr_info::CidrInfo,
+        ip_class::{IpClass, Scope},
+    };
 
     #[test]
     fn basic_cidr_0() {
         // Arrange
         let expected_addr_info = CidrInfo {
-            ip: Ipv4Addr::new(0, 0, 0, 0),
+            ip: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             cidr: 0,
-            subnet_mask: Ipv4Addr::new(0, 0, 0, 0),
-            wildcard_mask: Ipv4Addr::new(255, 255, 255, 255),
-            first_host_addr: Ipv4Addr::new(0, 0, 0, 1),
-            last_host_addr: Ipv4Addr::new(255, 255, 255, 254),
+            subnet_mask: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            wildcard_mask: IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)),
+            first_host_addr: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1)),
+            last_host_addr: IpAddr::V4(Ipv4Addr::new(255, 255, 255, 254)),
             usable_hosts: 4_294_967_294,
-            network_addr: Ipv4Addr::new(0, 0, 0, 0),
-            broadcast_addr: Ipv4Addr::new(255, 255, 255, 255),
+            network_addr: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            broadcast_addr: IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)),
             total_hosts: 4_294_967_296,
+            class: Some(IpClass::A),
+            scope: Some(Scope::default()),
         };
 
         // Act
-        let result_addr_info = CidrInfo::new(Ipv4Addr::from_str("0.0.0.0").unwrap(), 0).unwrap();
+        let result_addr_info =
+            CidrInfo::new(IpAddr::from_str("0.0.0.0").unwrap(), 0).unwrap();
 
         // Assert
         assert_eq!(result_addr_info, expected_addr_info);
@@ -114,20 +467,23 @@ mod test {
     fn basic_cidr_1() {
         // Arrange
         let expected_addr_info = CidrInfo {
-            ip: Ipv4Addr::new(0, 0, 0, 0),
+            ip: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             cidr: 1,
-            subnet_mask: Ipv4Addr::new(128, 0, 0, 0),
-            wildcard_mask: Ipv4Addr::new(127, 255, 255, 255),
-            first_host_addr: Ipv4Addr::new(0, 0, 0, 1),
-            last_host_addr: Ipv4Addr::new(127, 255, 255, 254),
+            subnet_mask: IpAddr::V4(Ipv4Addr::new(128, 0, 0, 0)),
+            wildcard_mask: IpAddr::V4(Ipv4Addr::new(127, 255, 255, 255)),
+            first_host_addr: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1)),
+            last_host_addr: IpAddr::V4(Ipv4Addr::new(127, 255, 255, 254)),
             usable_hosts: 2_147_483_646,
-            network_addr: Ipv4Addr::new(0, 0, 0, 0),
-            broadcast_addr: Ipv4Addr::new(127, 255, 255, 255),
+            network_addr: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            broadcast_addr: IpAddr::V4(Ipv4Addr::new(127, 255, 255, 255)),
             total_hosts: 2_147_483_648,
+            class: Some(IpClass::A),
+            scope: Some(Scope::default()),
         };
 
         // Act
-        let result_addr_info = CidrInfo::new(Ipv4Addr::from_str("0.0.0.0").unwrap(), 1).unwrap();
+        let result_addr_info =
+            CidrInfo::new(IpAddr::from_str("0.0.0.0").unwrap(), 1).unwrap();
 
         // Assert
         assert_eq!(result_addr_info, expected_addr_info);
@@ -137,21 +493,23 @@ mod test {
     fn basic_cidr_11() {
         // Arrange
         let expected_addr_info = CidrInfo {
-            ip: Ipv4Addr::new(255, 255, 255, 253),
+            ip: IpAddr::V4(Ipv4Addr::new(255, 255, 255, 253)),
             cidr: 11,
-            subnet_mask: Ipv4Addr::new(255, 224, 0, 0),
-            wildcard_mask: Ipv4Addr::new(0, 31, 255, 255),
-            first_host_addr: Ipv4Addr::new(255, 224, 0, 1),
-            last_host_addr: Ipv4Addr::new(255, 255, 255, 254),
+            subnet_mask: IpAddr::V4(Ipv4Addr::new(255, 224, 0, 0)),
+            wildcard_mask: IpAddr::V4(Ipv4Addr::new(0, 31, 255, 255)),
+            first_host_addr: IpAddr::V4(Ipv4Addr::new(255, 224, 0, 1)),
+            last_host_addr: IpAddr::V4(Ipv4Addr::new(255, 255, 255, 254)),
             usable_hosts: 2_097_150,
-            network_addr: Ipv4Addr::new(255, 224, 0, 0),
-            broadcast_addr: Ipv4Addr::new(255, 255, 255, 255),
+            network_addr: IpAddr::V4(Ipv4Addr::new(255, 224, 0, 0)),
+            broadcast_addr: IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)),
             total_hosts: 2_097_152,
+            class: Some(IpClass::E),
+            scope: Some(Scope::default()),
         };
 
         // Act
         let result_addr_info =
-            CidrInfo::new(Ipv4Addr::from_str("255.255.255.253").unwrap(), 11).unwrap();
+            CidrInfo::new(IpAddr::from_str("255.255.255.253").unwrap(), 11).unwrap();
 
         // Assert
         assert_eq!(result_addr_info, expected_addr_info);
@@ -160,20 +518,26 @@ mod test {
     fn basic_cidr_13() {
         // Arrange
         let expected_addr_info = CidrInfo {
-            ip: Ipv4Addr::new(10, 8, 17, 0),
+            ip: IpAddr::V4(Ipv4Addr::new(10, 8, 17, 0)),
             cidr: 13,
-            subnet_mask: Ipv4Addr::new(255, 248, 0, 0),
-            wildcard_mask: Ipv4Addr::new(0, 7, 255, 255),
-            first_host_addr: Ipv4Addr::new(10, 8, 0, 1),
-            last_host_addr: Ipv4Addr::new(10, 15, 255, 254),
+            subnet_mask: IpAddr::V4(Ipv4Addr::new(255, 248, 0, 0)),
+            wildcard_mask: IpAddr::V4(Ipv4Addr::new(0, 7, 255, 255)),
+            first_host_addr: IpAddr::V4(Ipv4Addr::new(10, 8, 0, 1)),
+            last_host_addr: IpAddr::V4(Ipv4Addr::new(10, 15, 255, 254)),
             usable_hosts: 524_286,
-            network_addr: Ipv4Addr::new(10, 8, 0, 0),
-            broadcast_addr: Ipv4Addr::new(10, 15, 255, 255),
+            network_addr: IpAddr::V4(Ipv4Addr::new(10, 8, 0, 0)),
+            broadcast_addr: IpAddr::V4(Ipv4Addr::new(10, 15, 255, 255)),
             total_hosts: 524_288,
+            class: Some(IpClass::A),
+            scope: Some(Scope {
+                private: true,
+                ..Scope::default()
+            }),
         };
 
         // Act
-        let result_addr_info = CidrInfo::new(Ipv4Addr::from_str("10.8.17.0").unwrap(), 13).unwrap();
+        let result_addr_info =
+            CidrInfo::new(IpAddr::from_str("10.8.17.0").unwrap(), 13).unwrap();
 
         // Assert
         assert_eq!(result_addr_info, expected_addr_info);
@@ -183,20 +547,26 @@ mod test {
     fn basic_cidr_24() {
         // Arrange
         let expected_addr_info = CidrInfo {
-            ip: Ipv4Addr::new(10, 0, 0, 1),
+            ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
             cidr: 24,
-            subnet_mask: Ipv4Addr::new(255, 255, 255, 0),
-            wildcard_mask: Ipv4Addr::new(0, 0, 0, 255),
-            first_host_addr: Ipv4Addr::new(10, 0, 0, 1),
-            last_host_addr: Ipv4Addr::new(10, 0, 0, 254),
-            network_addr: Ipv4Addr::new(10, 0, 0, 0),
-            broadcast_addr: Ipv4Addr::new(10, 0, 0, 255),
+            subnet_mask: IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0)),
+            wildcard_mask: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 255)),
+            first_host_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            last_host_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 254)),
+            network_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)),
+            broadcast_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 255)),
             usable_hosts: 254,
             total_hosts: 256,
+            class: Some(IpClass::A),
+            scope: Some(Scope {
+                private: true,
+                ..Scope::default()
+            }),
         };
 
         // Act
-        let result_addr_info = CidrInfo::new(Ipv4Addr::from_str("10.0.0.1").unwrap(), 24).unwrap();
+        let result_addr_info =
+            CidrInfo::new(IpAddr::from_str("10.0.0.1").unwrap(), 24).unwrap();
 
         // Assert
         assert_eq!(result_addr_info, expected_addr_info);
@@ -206,48 +576,235 @@ mod test {
     fn basic_cidr_31() {
         // Arrange
         let expected_addr_info = CidrInfo {
-            ip: Ipv4Addr::new(10, 0, 0, 1),
+            ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
             cidr: 31,
-            subnet_mask: Ipv4Addr::new(255, 255, 255, 254),
-            wildcard_mask: Ipv4Addr::new(0, 0, 0, 1),
-            first_host_addr: Ipv4Addr::new(10, 0, 0, 0),
-            last_host_addr: Ipv4Addr::new(10, 0, 0, 1),
-            network_addr: Ipv4Addr::new(10, 0, 0, 0),
-            broadcast_addr: Ipv4Addr::new(10, 0, 0, 1),
+            subnet_mask: IpAddr::V4(Ipv4Addr::new(255, 255, 255, 254)),
+            wildcard_mask: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1)),
+            first_host_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)),
+            last_host_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            network_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)),
+            broadcast_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
             usable_hosts: 0,
             total_hosts: 2,
+            class: Some(IpClass::A),
+            scope: Some(Scope {
+                private: true,
+                ..Scope::default()
+            }),
+        };
+
+        // Act
+        let result_addr_info =
+            CidrInfo::new(IpAddr::from_str("10.0.0.1").unwrap(), 31).unwrap();
+
+        // Assert
+        assert_eq!(result_addr_info, expected_addr_info);
+    }
+
+    #[test]
+    fn basic_cidr_v6_64() {
+        // Arrange
+        let expected_addr_info = CidrInfo {
+            ip: IpAddr::V6(Ipv6Addr::from_str("2001:db8::1").unwrap()),
+            cidr: 64,
+            subnet_mask: IpAddr::V6(Ipv6Addr::from_str("ffff:ffff:ffff:ffff::").unwrap()),
+            wildcard_mask: IpAddr::V6(Ipv6Addr::from_str("::ffff:ffff:ffff:ffff").unwrap()),
+            first_host_addr: IpAddr::V6(Ipv6Addr::from_str("2001:db8::1").unwrap()),
+            last_host_addr: IpAddr::V6(
+                Ipv6Addr::from_str("2001:db8::ffff:ffff:ffff:fffe").unwrap(),
+            ),
+            usable_hosts: 18_446_744_073_709_551_614,
+            network_addr: IpAddr::V6(Ipv6Addr::from_str("2001:db8::").unwrap()),
+            broadcast_addr: IpAddr::V6(
+                Ipv6Addr::from_str("2001:db8::ffff:ffff:ffff:ffff").unwrap(),
+            ),
+            total_hosts: 18_446_744_073_709_551_616,
+            class: None,
+            scope: None,
         };
 
         // Act
-        let result_addr_info = CidrInfo::new(Ipv4Addr::from_str("10.0.0.1").unwrap(), 31).unwrap();
+        let result_addr_info =
+            CidrInfo::new(IpAddr::from_str("2001:db8::1").unwrap(), 64).unwrap();
 
         // Assert
         assert_eq!(result_addr_info, expected_addr_info);
     }
 
-    // #[test]
-    // #[should_panic]
-    // fn too_large_octet_ipv4() {
-    //     let addr_info = CidrInfo::new("256.0.0.0/32");
-    //     assert_eq!(addr_info.ip, Ipv4Addr::new(0, 0, 0, 0));
-    // }
-    //
-    // #[test]
-    // #[should_panic]
-    // fn too_small_octet_ipv4() {
-    //     let addr_info = CidrInfo::new("-1.0.0.0/32");
-    //     assert_eq!(addr_info.ip, Ipv4Addr::new(0, 0, 0, 0));
-    // }
-    //
-    // #[test]
-    // #[should_panic]
-    // fn too_large_cider() {
-    //     let _ = CidrInfo::new("0.0.0.0/33");
-    // }
-    //
-    // #[test]
-    // #[should_panic]
-    // fn too_small_cider() {
-    //     let _ = CidrInfo::new("0.0.0.0/-1");
-    // }
+    #[test]
+    fn cidr_out_of_range() {
+        assert!(CidrInfo::new(IpAddr::from_str("10.0.0.1").unwrap(), 33).is_err());
+        assert!(CidrInfo::new(IpAddr::from_str("::1").unwrap(), 129).is_err());
+    }
+
+    #[test]
+    fn new_strict_rejects_host_bits() {
+        assert!(CidrInfo::new_strict(IpAddr::from_str("10.0.0.5").unwrap(), 30).is_err());
+        assert!(CidrInfo::new_strict(IpAddr::from_str("10.0.0.4").unwrap(), 30).is_ok());
+    }
+
+    #[test]
+    fn contains_checks_membership() {
+        let block = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 24).unwrap();
+
+        assert!(block.contains(IpAddr::from_str("10.0.0.0").unwrap()));
+        assert!(block.contains(IpAddr::from_str("10.0.0.128").unwrap()));
+        assert!(block.contains(IpAddr::from_str("10.0.0.255").unwrap()));
+        assert!(!block.contains(IpAddr::from_str("10.0.1.0").unwrap()));
+        assert!(!block.contains(IpAddr::from_str("::1").unwrap()));
+    }
+
+    #[test]
+    fn hosts_crosses_octet_boundary() {
+        let block = CidrInfo::new(IpAddr::from_str("10.8.17.0").unwrap(), 23).unwrap();
+        let hosts: Vec<IpAddr> = block.hosts().collect();
+
+        assert_eq!(hosts.first(), Some(&IpAddr::from_str("10.8.16.1").unwrap()));
+        assert_eq!(hosts.last(), Some(&IpAddr::from_str("10.8.17.254").unwrap()));
+        assert_eq!(hosts.len(), 510);
+    }
+
+    #[test]
+    fn hosts_empty_for_point_to_point_link() {
+        let block = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 31).unwrap();
+        assert_eq!(block.hosts().count(), 0);
+    }
+
+    #[test]
+    fn overlaps_detects_shared_ranges() {
+        let a = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 24).unwrap();
+        let b = CidrInfo::new(IpAddr::from_str("10.0.0.128").unwrap(), 25).unwrap();
+        let c = CidrInfo::new(IpAddr::from_str("10.0.1.0").unwrap(), 24).unwrap();
+        let v6 = CidrInfo::new(IpAddr::from_str("2001:db8::").unwrap(), 32).unwrap();
+
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        assert!(!a.overlaps(&c));
+        assert!(!a.overlaps(&v6));
+    }
+
+    #[test]
+    fn is_subnet_and_supernet_of() {
+        let parent = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 16).unwrap();
+        let child = CidrInfo::new(IpAddr::from_str("10.0.5.0").unwrap(), 24).unwrap();
+        let unrelated = CidrInfo::new(IpAddr::from_str("192.168.0.0").unwrap(), 24).unwrap();
+
+        assert!(child.is_subnet_of(&parent));
+        assert!(parent.is_supernet_of(&child));
+        assert!(!parent.is_subnet_of(&child));
+        assert!(!unrelated.is_subnet_of(&parent));
+    }
+
+    #[test]
+    fn aggregate_merges_adjacent_equal_sized_blocks() {
+        let a = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 25).unwrap();
+        let b = CidrInfo::new(IpAddr::from_str("10.0.0.128").unwrap(), 25).unwrap();
+
+        let aggregated = CidrInfo::aggregate(&[a, b]);
+
+        assert_eq!(aggregated.len(), 1);
+        assert_eq!(aggregated[0].network_addr, IpAddr::from_str("10.0.0.0").unwrap());
+        assert_eq!(aggregated[0].cidr, 24);
+    }
+
+    #[test]
+    fn aggregate_keeps_unrelated_blocks_separate() {
+        let a = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 24).unwrap();
+        let b = CidrInfo::new(IpAddr::from_str("192.168.0.0").unwrap(), 24).unwrap();
+
+        let aggregated = CidrInfo::aggregate(&[a, b]);
+
+        assert_eq!(aggregated.len(), 2);
+    }
+
+    #[test]
+    fn aggregate_decomposes_misaligned_merge_into_multiple_blocks() {
+        let a = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 24).unwrap();
+        let b = CidrInfo::new(IpAddr::from_str("10.0.1.0").unwrap(), 25).unwrap();
+
+        let aggregated = CidrInfo::aggregate(&[a, b]);
+
+        assert_eq!(aggregated.len(), 2);
+        assert_eq!(aggregated[0].network_addr, IpAddr::from_str("10.0.0.0").unwrap());
+        assert_eq!(aggregated[0].cidr, 24);
+        assert_eq!(aggregated[1].network_addr, IpAddr::from_str("10.0.1.0").unwrap());
+        assert_eq!(aggregated[1].cidr, 25);
+    }
+
+    #[test]
+    fn addresses_includes_network_and_broadcast() {
+        let block = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 30).unwrap();
+        let addresses: Vec<IpAddr> = block.addresses().collect();
+
+        assert_eq!(addresses.first(), Some(&IpAddr::from_str("10.0.0.0").unwrap()));
+        assert_eq!(addresses.last(), Some(&IpAddr::from_str("10.0.0.3").unwrap()));
+        assert_eq!(addresses.len(), 4);
+    }
+
+    #[test]
+    fn split_into_divides_evenly() {
+        let block = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 24).unwrap();
+        let children = block.split_into(26).unwrap();
+
+        assert_eq!(children.len(), 4);
+        assert_eq!(children[0].network_addr, IpAddr::from_str("10.0.0.0").unwrap());
+        assert_eq!(children[1].network_addr, IpAddr::from_str("10.0.0.64").unwrap());
+        assert_eq!(children[2].network_addr, IpAddr::from_str("10.0.0.128").unwrap());
+        assert_eq!(children[3].network_addr, IpAddr::from_str("10.0.0.192").unwrap());
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let block = CidrInfo::new(IpAddr::from_str("10.0.0.1").unwrap(), 24).unwrap();
+        let bytes = block.to_bytes().unwrap();
+
+        assert_eq!(bytes, [10, 0, 0, 1, 24]);
+        assert_eq!(CidrInfo::from_bytes(&bytes).unwrap(), block);
+    }
+
+    #[test]
+    fn from_bytes_rejects_short_input() {
+        assert!(CidrInfo::from_bytes(&[10, 0, 0, 1]).is_err());
+    }
+
+    #[test]
+    fn to_bytes_rejects_ipv6() {
+        let block = CidrInfo::new(IpAddr::from_str("2001:db8::1").unwrap(), 64).unwrap();
+        assert!(block.to_bytes().is_err());
+    }
+
+    #[test]
+    fn split_by_hosts_packs_largest_first_aligned() {
+        let block = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 24).unwrap();
+        let children = block.split_by_hosts(&[50, 10, 100]).unwrap();
+
+        assert_eq!(children.len(), 3);
+        // 100 hosts needs a /25 (126 usable), placed first at the start of the block.
+        assert_eq!(children[2].network_addr, IpAddr::from_str("10.0.0.0").unwrap());
+        assert_eq!(children[2].cidr, 25);
+        // 50 hosts needs a /26 (62 usable), the next aligned block after the /25.
+        assert_eq!(children[0].network_addr, IpAddr::from_str("10.0.0.128").unwrap());
+        assert_eq!(children[0].cidr, 26);
+        // 10 hosts needs a /28 (14 usable), the next aligned block after the /26.
+        assert_eq!(children[1].network_addr, IpAddr::from_str("10.0.0.192").unwrap());
+        assert_eq!(children[1].cidr, 28);
+    }
+
+    #[test]
+    fn split_by_hosts_errors_when_parent_is_too_small() {
+        let block = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 30).unwrap();
+        assert!(block.split_by_hosts(&[100]).is_err());
+    }
+
+    #[test]
+    fn split_into_rejects_shorter_prefix() {
+        let block = CidrInfo::new(IpAddr::from_str("10.0.0.0").unwrap(), 24).unwrap();
+        assert!(block.split_into(23).is_err());
+    }
+
+    #[test]
+    fn split_into_rejects_when_result_would_be_too_large() {
+        let block = CidrInfo::new(IpAddr::from_str("0.0.0.0").unwrap(), 0).unwrap();
+        assert!(block.split_into(32).is_err());
+    }
 }
@@ -1,20 +1,34 @@
-pub const USAGE_MSG: &str =
-    "format must be X.X.X.X/X (ex: 10.0.0.1/24), delimited by \".\", or \"-\"";
+pub const USAGE_MSG: &str = "format must be X.X.X.X/X (ex: 10.0.0.1/24), delimited by \".\", or \"-\", or an IPv6 address/prefix (ex: 2001:db8::1/64)";
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
-    #[error("CIDR must be unsigned integer in range 0-32 inclusive")]
+    #[error("CIDR must be an unsigned integer: {0}")]
     InvalidCidr(#[from] std::num::ParseIntError),
 
-    #[error("CIDR must be in range 0-32 inclusive: provided = {0}")]
-    CidrOutOfRange(u8),
+    #[error("CIDR must be in range 0-{1} inclusive: provided = {0}")]
+    CidrOutOfRange(u8, u8),
 
-    #[error("IP must be a vaild Ipv4 Address: {}", USAGE_MSG)]
+    #[error("IP must be a valid address: {0}")]
     InvalidIp(#[from] std::net::AddrParseError),
 
     #[error("{0} is not valid! Make sure you are using a consistent pattern: {USAGE_MSG}")]
     InvalidFormat(String),
 
-    #[error("Make sure you are using a consistent pattern: {}", USAGE_MSG)]
-    Format(String),
+    #[error("address has host bits set outside the network prefix; use the network address instead")]
+    HostBitsTooLarge,
+
+    #[error("split prefix /{0} must be longer than the parent prefix /{1}")]
+    SplitPrefixTooSmall(u8, u8),
+
+    #[error("splitting /{1} into /{0} would produce {2} subnets, which exceeds the limit of {3}")]
+    SplitTooLarge(u8, u8, u128, u128),
+
+    #[error("parent block is too small to fit all of the requested subnet sizes")]
+    SplitExhausted,
+
+    #[error("byte form must be at least 5 bytes long (4 address octets + prefix): got {0}")]
+    InvalidSize(usize),
+
+    #[error("the 5-byte wire form only supports IPv4 addresses")]
+    NotIpv4,
 }
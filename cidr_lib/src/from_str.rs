@@ -1,6 +1,7 @@
-use std::{net::Ipv4Addr, str::FromStr};
-
-use regex::Regex;
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
 
 use crate::{cidr_info::CidrInfo, error::Error};
 
@@ -13,94 +14,178 @@ impl FromStr for CidrInfo {
     }
 }
 
-fn parse_ip(os: [&str; 4]) -> Result<Ipv4Addr, Error> {
-    Ok(Ipv4Addr::from_str(&format!(
-        "{}.{}.{}.{}",
-        os[0], os[1], os[2], os[3]
-    ))?)
+/// A tiny byte-oriented backtracking parser, modeled on the one std uses internally
+/// for `IpAddr::from_str`.
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
 }
 
-fn parse_cidr(input: &str) -> Result<u8, Error> {
-    let n = input.parse::<u8>()?;
-    if n > 32 {
-        return Err(Error::CidrOutOfRange(n));
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser {
+            data: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn is_eof(&self) -> bool {
+        self.pos == self.data.len()
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.data.get(self.pos).map(|&b| b as char)
+    }
+
+    fn advance(&mut self) {
+        self.pos += 1;
+    }
+
+    /// Runs `f`, rewinding back to the starting position if it returns `None` so
+    /// the caller can try a different alternative without having consumed input.
+    fn read_atomically<T>(&mut self, f: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        let start = self.pos;
+        let result = f(self);
+        if result.is_none() {
+            self.pos = start;
+        }
+        result
+    }
+
+    fn accept_char(&mut self, c: char) -> Option<()> {
+        if self.peek_char() == Some(c) {
+            self.advance();
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn accept_digit(&mut self) -> Option<u8> {
+        let digit = self.peek_char()?.to_digit(10)? as u8;
+        self.advance();
+        Some(digit)
+    }
+
+    /// Accepts a run of up to `max_digits` decimal digits whose value is `<= max_value`.
+    fn accept_number(&mut self, max_digits: u32, max_value: u32) -> Option<u8> {
+        self.read_atomically(|p| {
+            let mut value: u32 = 0;
+            let mut digits: u32 = 0;
+
+            while let Some(digit) = p.accept_digit() {
+                value = value * 10 + digit as u32;
+                digits += 1;
+                if digits > max_digits || value > max_value {
+                    return None;
+                }
+            }
+
+            if digits == 0 {
+                None
+            } else {
+                Some(value as u8)
+            }
+        })
+    }
+
+    fn accept_octets_and_cidr(&mut self, octet_delim: char, cidr_delim: char) -> Option<(Ipv4Addr, u8)> {
+        self.read_atomically(|p| {
+            let o1 = p.accept_number(3, 255)?;
+            p.accept_char(octet_delim)?;
+            let o2 = p.accept_number(3, 255)?;
+            p.accept_char(octet_delim)?;
+            let o3 = p.accept_number(3, 255)?;
+            p.accept_char(octet_delim)?;
+            let o4 = p.accept_number(3, 255)?;
+            p.accept_char(cidr_delim)?;
+            let cidr = p.accept_number(2, 32)?;
+            Some((Ipv4Addr::new(o1, o2, o3, o4), cidr))
+        })
     }
-    Ok(n)
 }
 
-pub fn parse_ip_and_cidr(input: String) -> Result<(Ipv4Addr, u8), Error> {
-    let re = Regex::new(r"^(?<octet_1>(\d){1,3})(\.|\-)(?<octet_2>(\d){1,3})(\.|\-)(?<octet_3>(\d){1,3})(\.|\-)(?<octet_4>(\d){1,3})(\/|\-)(?<cidr>(\d){1,2})$").unwrap();
-
-    let Some(parts) = re.captures(&input) else {
-        return Err(Error::InvalidFormat(input.to_string()));
-    };
-
-    Ok((
-        parse_ip([
-            &parts["octet_1"],
-            &parts["octet_2"],
-            &parts["octet_3"],
-            &parts["octet_4"],
-        ])?,
-        parse_cidr(&parts["cidr"])?,
-    ))
+/// Parses either a dotted/dashed IPv4 literal (via [`Parser`]) or a standard
+/// `addr/prefix` IPv6 literal, detecting the family from the presence of `:`.
+pub fn parse_ip_and_cidr(input: String) -> Result<(IpAddr, u8), Error> {
+    if input.contains(':') {
+        return parse_ipv6_and_cidr(&input);
+    }
+
+    parse_ipv4_and_cidr(&input).ok_or(Error::InvalidFormat(input))
 }
 
-#[cfg(test)]
-mod test {
-    use super::parse_ip_and_cidr;
-    use crate::{
-        error::Error,
-        from_str::{parse_cidr, parse_ip},
-    };
-    use pretty_assertions::assert_eq;
-    use rstest::rstest;
-    use std::net::Ipv4Addr;
+fn parse_ipv4_and_cidr(input: &str) -> Option<(IpAddr, u8)> {
+    let mut parser = Parser::new(input);
 
-    #[rstest]
-    #[case(["255", "255", "255", "255"], Ipv4Addr::new(255, 255, 255, 255))]
-    #[case(["1","1","1","1"], Ipv4Addr::new(1, 1, 1, 1))]
-    #[case(["0","0","0","0"], Ipv4Addr::new(0, 0, 0, 0))]
-    fn test_parse_ip(#[case] input: [&str; 4], #[case] expected: Ipv4Addr) -> Result<(), Error> {
-        assert_eq!(parse_ip(input)?, expected);
-        Ok(())
-    }
+    let parsed = parser
+        .accept_octets_and_cidr('.', '/')
+        .or_else(|| parser.accept_octets_and_cidr('-', '-'));
 
-    #[rstest]
-    #[case::too_big_ip(["256","256","256","256"])]
-    #[case::too_small_ip(["-1","-1","-1","-1"])]
-    #[should_panic]
-    fn test_parse_ip_invalid(#[case] input: [&str; 4]) {
-        parse_ip(input).unwrap();
+    match parsed {
+        Some((ip, cidr)) if parser.is_eof() => Some((IpAddr::V4(ip), cidr)),
+        _ => None,
     }
+}
 
-    #[rstest]
-    #[case("32", 32)]
-    #[case("16", 16)]
-    #[case("0", 0)]
-    fn test_parse_cidr(#[case] input: &str, #[case] expected: u8) {
-        assert_eq!(parse_cidr(input).unwrap(), expected);
+/// IPv6 has no dashed form and `::` compression makes a hand-rolled parser
+/// impractical to justify here, so this leans on std's well-tested `Ipv6Addr`
+/// parser for the address half and only validates the `/prefix` half.
+///
+/// Unlike the v4 path, failures of the address or prefix half carry the
+/// underlying parse error (`InvalidIp`/`InvalidCidr`, or `CidrOutOfRange` for a
+/// prefix over 128); only input with no `/` at all yields `InvalidFormat`.
+fn parse_ipv6_and_cidr(input: &str) -> Result<(IpAddr, u8), Error> {
+    let (addr, cidr) = input
+        .rsplit_once('/')
+        .ok_or_else(|| Error::InvalidFormat(input.to_string()))?;
+
+    let ip: Ipv6Addr = addr.parse()?;
+    let cidr: u8 = cidr.parse()?;
+    if cidr > 128 {
+        return Err(Error::CidrOutOfRange(cidr, 128));
     }
 
+    Ok((IpAddr::V6(ip), cidr))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_ip_and_cidr, Parser};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
     #[rstest]
-    #[case::too_big_cidr("256")]
-    #[case::too_big_cidr("33")]
-    #[case::too_small_cidr("-1")]
-    #[should_panic]
-    fn test_parse_cidr_invalid(#[case] input: &str) {
-        parse_cidr(input).unwrap();
+    #[case("255", 3, 255, Some(255))]
+    #[case("32", 2, 32, Some(32))]
+    #[case("33", 2, 32, None)]
+    #[case("256", 3, 255, None)]
+    #[case("1000", 3, 255, None)]
+    #[case("", 3, 255, None)]
+    fn test_accept_number(
+        #[case] input: &str,
+        #[case] max_digits: u32,
+        #[case] max_value: u32,
+        #[case] expected: Option<u8>,
+    ) {
+        let mut parser = Parser::new(input);
+        assert_eq!(parser.accept_number(max_digits, max_value), expected);
     }
 
     #[rstest]
-    #[case("0.0.0.0/0", (Ipv4Addr::new(0, 0, 0, 0), 0))]
-    #[case("0-0-0-0-0", (Ipv4Addr::new(0, 0, 0, 0), 0))]
-    #[case("0.0.0.1/1", (Ipv4Addr::new(0, 0, 0, 1), 1))]
-    #[case("0-0-0-1-1", (Ipv4Addr::new(0, 0, 0, 1), 1))]
-    #[case("192.168.1.0/24", (Ipv4Addr::new(192, 168, 1, 0), 24))]
-    #[case("192-168-1-0-24", (Ipv4Addr::new(192, 168, 1, 0), 24))]
-    #[case("255.255.255.255/32", (Ipv4Addr::new(255, 255, 255, 255), 32))]
-    #[case("255-255-255-255-32", (Ipv4Addr::new(255, 255, 255, 255), 32))]
-    fn test_parse_ip_cidr_string(#[case] input: &str, #[case] expected: (Ipv4Addr, u8)) {
+    #[case("0.0.0.0/0", (IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 0))]
+    #[case("0-0-0-0-0", (IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 0))]
+    #[case("0.0.0.1/1", (IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1)), 1))]
+    #[case("0-0-0-1-1", (IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1)), 1))]
+    #[case("192.168.1.0/24", (IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24))]
+    #[case("192-168-1-0-24", (IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24))]
+    #[case("255.255.255.255/32", (IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)), 32))]
+    #[case("255-255-255-255-32", (IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)), 32))]
+    #[case("2001:db8::1/64", (IpAddr::V6(Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1)), 64))]
+    #[case("::/0", (IpAddr::V6(Ipv6Addr::UNSPECIFIED), 0))]
+    #[case("::1/128", (IpAddr::V6(Ipv6Addr::LOCALHOST), 128))]
+    fn test_parse_ip_cidr_string(#[case] input: &str, #[case] expected: (IpAddr, u8)) {
         // Arrange / Act / Assert
         assert_eq!(parse_ip_and_cidr(input.to_string()).unwrap(), expected);
     }
@@ -111,6 +196,9 @@ mod test {
     #[case::too_big_ip("256.256.256.256/1")]
     #[case::too_small_ip("-1.-1.-1.-1/1")]
     #[case::multi_format("0-0-0-0/33")]
+    #[case::trailing_garbage("0.0.0.0/24extra")]
+    #[case::too_big_v6_cidr("2001:db8::1/129")]
+    #[case::malformed_v6("2001:db8:::1/64")]
     #[should_panic]
     fn test_parse_ip_cidr_string_invalid(#[case] input: &str) {
         // Arrange / Act / Assert
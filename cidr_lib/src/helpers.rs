@@ -1,83 +1,88 @@
-use std::net::Ipv4Addr;
-
-pub fn get_subnet_mask(cidr: u8) -> Ipv4Addr {
-    let wildcard_bits = 32 - cidr;
-    let mask_bits: String = format!(
-        "{}{}",
-        "1".repeat(cidr.into()),
-        "0".repeat(wildcard_bits.into())
-    );
-
-    Ipv4Addr::new(
-        u8::from_str_radix(&mask_bits[..8], 2).expect("bits should only contain 0 or 1."),
-        u8::from_str_radix(&mask_bits[8..16], 2).expect("bits should only contain 0 or 1."),
-        u8::from_str_radix(&mask_bits[16..24], 2).expect("bits should only contain 0 or 1."),
-        u8::from_str_radix(&mask_bits[24..32], 2).expect("bits should only contain 0 or 1."),
-    )
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Maximum valid prefix length for the address family of `ip` (32 for IPv4, 128 for IPv6).
+pub fn max_prefix(ip: IpAddr) -> u8 {
+    match ip {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+pub(crate) fn addr_to_u128(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(v4) => u32::from(v4) as u128,
+        IpAddr::V6(v6) => u128::from(v6),
+    }
+}
+
+/// Reconstructs an address from an integer value, matching the family of `like`.
+pub(crate) fn u128_to_addr(value: u128, like: IpAddr) -> IpAddr {
+    match like {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(value as u32)),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(value)),
+    }
+}
+
+/// All `width` bits set (e.g. `u32::MAX` as a `u128` for `width == 32`).
+fn full_mask(width: u8) -> u128 {
+    if width == 128 {
+        u128::MAX
+    } else {
+        (1u128 << width) - 1
+    }
+}
+
+/// The top `prefix` bits set within a `width`-bit address space.
+fn prefix_mask(prefix: u8, width: u8) -> u128 {
+    if prefix == 0 {
+        0
+    } else {
+        (u128::MAX << (128 - prefix)) >> (128 - width)
+    }
+}
+
+pub fn get_subnet_mask(ip: IpAddr, cidr: u8) -> IpAddr {
+    u128_to_addr(prefix_mask(cidr, max_prefix(ip)), ip)
 }
 
-pub fn get_wildcard_mask(mask_subnet: Ipv4Addr) -> Ipv4Addr {
-    let mask_subnet_octets = mask_subnet.octets();
-    Ipv4Addr::new(
-        u8::MAX - mask_subnet_octets[0],
-        u8::MAX - mask_subnet_octets[1],
-        u8::MAX - mask_subnet_octets[2],
-        u8::MAX - mask_subnet_octets[3],
-    )
+pub fn get_wildcard_mask(mask_subnet: IpAddr) -> IpAddr {
+    let width = max_prefix(mask_subnet);
+    let wildcard = full_mask(width) & !addr_to_u128(mask_subnet);
+    u128_to_addr(wildcard, mask_subnet)
 }
 
-pub fn get_network_addr(mask_subnet: Ipv4Addr, ip: Ipv4Addr) -> Ipv4Addr {
-    let mask_subnet_octets = mask_subnet.octets();
-    let ip_octets = ip.octets();
-    Ipv4Addr::new(
-        mask_subnet_octets[0] & ip_octets[0],
-        mask_subnet_octets[1] & ip_octets[1],
-        mask_subnet_octets[2] & ip_octets[2],
-        mask_subnet_octets[3] & ip_octets[3],
-    )
+pub fn get_network_addr(mask_subnet: IpAddr, ip: IpAddr) -> IpAddr {
+    u128_to_addr(addr_to_u128(mask_subnet) & addr_to_u128(ip), ip)
 }
 
-pub fn get_first_host_addr(addr_network: Ipv4Addr, hosts_usable: u64) -> Ipv4Addr {
+pub fn get_first_host_addr(addr_network: IpAddr, hosts_usable: u128) -> IpAddr {
     if hosts_usable == 0 {
         return addr_network;
     }
 
-    let addr_network_octets = addr_network.octets();
-    Ipv4Addr::new(
-        addr_network_octets[0],
-        addr_network_octets[1],
-        addr_network_octets[2],
-        addr_network_octets[3] + 1,
-    )
+    u128_to_addr(addr_to_u128(addr_network) + 1, addr_network)
 }
 
-pub fn get_broadcast_addr(mask_wildcard: Ipv4Addr, ip: Ipv4Addr) -> Ipv4Addr {
-    let mask_wildcard_octets = mask_wildcard.octets();
-    let ip_octets = ip.octets();
-
-    Ipv4Addr::new(
-        ip_octets[0] | mask_wildcard_octets[0],
-        ip_octets[1] | mask_wildcard_octets[1],
-        ip_octets[2] | mask_wildcard_octets[2],
-        ip_octets[3] | mask_wildcard_octets[3],
-    )
+pub fn get_broadcast_addr(mask_wildcard: IpAddr, ip: IpAddr) -> IpAddr {
+    u128_to_addr(addr_to_u128(ip) | addr_to_u128(mask_wildcard), ip)
 }
 
-pub fn get_last_host_addr(addr_broadcast: Ipv4Addr, hosts_usable: u64) -> Ipv4Addr {
+pub fn get_last_host_addr(addr_broadcast: IpAddr, hosts_usable: u128) -> IpAddr {
     if hosts_usable == 0 {
         return addr_broadcast;
     }
-    let addr_broadcast_octets = addr_broadcast.octets();
-    Ipv4Addr::new(
-        addr_broadcast_octets[0],
-        addr_broadcast_octets[1],
-        addr_broadcast_octets[2],
-        addr_broadcast_octets[3] - 1,
-    )
+
+    u128_to_addr(addr_to_u128(addr_broadcast) - 1, addr_broadcast)
 }
 
-pub fn get_host_values(cidr: u8) -> (u64, u64) {
-    let total = 1 << (32 - cidr);
+/// Returns `(total_hosts, usable_hosts)` for `cidr` in the address family of `ip`.
+///
+/// `total_hosts` saturates at `u128::MAX` for a `/0` IPv6 network, since `2^128`
+/// does not fit in a `u128`.
+pub fn get_host_values(ip: IpAddr, cidr: u8) -> (u128, u128) {
+    let total = 1u128
+        .checked_shl((max_prefix(ip) - cidr) as u32)
+        .unwrap_or(u128::MAX);
     if total >= 2 {
         return (total, total - 2);
     }
@@ -88,7 +93,7 @@ pub fn get_host_values(cidr: u8) -> (u64, u64) {
 mod test {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
-    use std::net::Ipv4Addr;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
     use crate::helpers::{
         get_broadcast_addr, get_first_host_addr, get_host_values, get_last_host_addr,
@@ -96,68 +101,83 @@ mod test {
     };
 
     #[rstest]
-    #[case(0, Ipv4Addr::new(0, 0, 0, 0))]
-    #[case(8, Ipv4Addr::new(255, 0, 0, 0))]
-    #[case(16, Ipv4Addr::new(255, 255, 0, 0))]
-    #[case(24, Ipv4Addr::new(255, 255, 255, 0))]
-    #[case(25, Ipv4Addr::new(255, 255, 255, 128))]
-    #[case(32, Ipv4Addr::new(255, 255, 255, 255))]
-    fn test_get_subnet_mask(#[case] input: u8, #[case] expected: Ipv4Addr) {
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0, IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)))]
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 8, IpAddr::V4(Ipv4Addr::new(255, 0, 0, 0)))]
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 16, IpAddr::V4(Ipv4Addr::new(255, 255, 0, 0)))]
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 24, IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0)))]
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 25, IpAddr::V4(Ipv4Addr::new(255, 255, 255, 128)))]
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 32, IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)))]
+    #[case(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 0, IpAddr::V6(Ipv6Addr::UNSPECIFIED))]
+    #[case(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 128, IpAddr::V6(Ipv6Addr::from(u128::MAX)))]
+    fn test_get_subnet_mask(#[case] ip: IpAddr, #[case] cidr: u8, #[case] expected: IpAddr) {
         // Arrange / Act / Assert
-        assert_eq!(get_subnet_mask(input), expected);
+        assert_eq!(get_subnet_mask(ip, cidr), expected);
     }
 
     #[rstest]
-    #[case(Ipv4Addr::new(0, 0, 0, 0), Ipv4Addr::new(255, 255, 255, 255))]
-    #[case(Ipv4Addr::new(255, 0, 0, 0), Ipv4Addr::new(0, 255, 255, 255))]
-    #[case(Ipv4Addr::new(255, 255, 0, 0), Ipv4Addr::new(0, 0, 255, 255))]
-    #[case(Ipv4Addr::new(255, 255, 255, 0), Ipv4Addr::new(0, 0, 0, 255))]
-    #[case(Ipv4Addr::new(255, 255, 255, 128), Ipv4Addr::new(0, 0, 0, 127))]
-    #[case(Ipv4Addr::new(255, 255, 255, 255), Ipv4Addr::new(0, 0, 0, 0))]
-    fn test_get_wildcard_mask(#[case] input: Ipv4Addr, #[case] expected: Ipv4Addr) {
+    #[case(
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+        IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255))
+    )]
+    #[case(
+        IpAddr::V4(Ipv4Addr::new(255, 0, 0, 0)),
+        IpAddr::V4(Ipv4Addr::new(0, 255, 255, 255))
+    )]
+    #[case(
+        IpAddr::V4(Ipv4Addr::new(255, 255, 0, 0)),
+        IpAddr::V4(Ipv4Addr::new(0, 0, 255, 255))
+    )]
+    #[case(
+        IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0)),
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 255))
+    )]
+    #[case(
+        IpAddr::V4(Ipv4Addr::new(255, 255, 255, 128)),
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 127))
+    )]
+    #[case(
+        IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)),
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0))
+    )]
+    fn test_get_wildcard_mask(#[case] input: IpAddr, #[case] expected: IpAddr) {
         // Arrange / Act / Assert
         assert_eq!(get_wildcard_mask(input), expected);
     }
 
     #[rstest]
     #[case(
-        Ipv4Addr::new(0, 0, 0, 0),
-        Ipv4Addr::new(1, 2, 3, 4),
-        Ipv4Addr::new(0, 0, 0, 0)
-    )]
-    #[case(
-        Ipv4Addr::new(255, 255, 255, 0),
-        Ipv4Addr::new(1, 2, 3, 4),
-        Ipv4Addr::new(1, 2, 3, 0)
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0))
     )]
     #[case(
-        Ipv4Addr::new(255, 255, 255, 0),
-        Ipv4Addr::new(1, 2, 3, 4),
-        Ipv4Addr::new(1, 2, 3, 0)
+        IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 0))
     )]
     #[case(
-        Ipv4Addr::new(255, 255, 0, 0),
-        Ipv4Addr::new(1, 2, 3, 4),
-        Ipv4Addr::new(1, 2, 0, 0)
+        IpAddr::V4(Ipv4Addr::new(255, 255, 0, 0)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 0, 0))
     )]
     fn test_get_network_addr(
-        #[case] input1: Ipv4Addr,
-        #[case] input2: Ipv4Addr,
-        #[case] expected: Ipv4Addr,
+        #[case] input1: IpAddr,
+        #[case] input2: IpAddr,
+        #[case] expected: IpAddr,
     ) {
         // Arrange / Act / Assert
         assert_eq!(get_network_addr(input1, input2), expected);
     }
 
     #[rstest]
-    #[case(Ipv4Addr::new(1, 2, 3, 4), 1, Ipv4Addr::new(1, 2, 3, 5))]
-    #[case(Ipv4Addr::new(1, 2, 3, 0), 1, Ipv4Addr::new(1, 2, 3, 1))]
-    #[case(Ipv4Addr::new(0, 0, 0, 0), 1, Ipv4Addr::new(0, 0, 0, 1))]
-    #[case(Ipv4Addr::new(10, 0, 0, 1), 0, Ipv4Addr::new(10, 0, 0, 1))]
+    #[case(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)), 1, IpAddr::V4(Ipv4Addr::new(1, 2, 3, 5)))]
+    #[case(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 0)), 1, IpAddr::V4(Ipv4Addr::new(1, 2, 3, 1)))]
+    #[case(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 1, IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1)))]
+    #[case(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 0, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))]
     fn test_get_first_host_addr(
-        #[case] input1: Ipv4Addr,
-        #[case] input2: u64,
-        #[case] expected: Ipv4Addr,
+        #[case] input1: IpAddr,
+        #[case] input2: u128,
+        #[case] expected: IpAddr,
     ) {
         // Arrange / Act / Assert
         assert_eq!(get_first_host_addr(input1, input2), expected,);
@@ -165,48 +185,50 @@ mod test {
 
     #[rstest]
     #[case(
-        Ipv4Addr::new(0, 0, 0, 127),
-        Ipv4Addr::new(1, 2, 3, 4),
-        Ipv4Addr::new(1, 2, 3, 127)
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 127)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 127))
     )]
     #[case(
-        Ipv4Addr::new(0, 255, 255, 255),
-        Ipv4Addr::new(1, 2, 3, 4),
-        Ipv4Addr::new(1, 255, 255, 255)
+        IpAddr::V4(Ipv4Addr::new(0, 255, 255, 255)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+        IpAddr::V4(Ipv4Addr::new(1, 255, 255, 255))
     )]
     #[case(
-        Ipv4Addr::new(0, 0, 0, 1),
-        Ipv4Addr::new(1, 2, 3, 4),
-        Ipv4Addr::new(1, 2, 3, 5)
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)),
+        IpAddr::V4(Ipv4Addr::new(1, 2, 3, 5))
     )]
     fn test_get_broadcast_addr(
-        #[case] input1: Ipv4Addr,
-        #[case] input2: Ipv4Addr,
-        #[case] expected: Ipv4Addr,
+        #[case] input1: IpAddr,
+        #[case] input2: IpAddr,
+        #[case] expected: IpAddr,
     ) {
         // Arrange / Act / Assert
         assert_eq!(get_broadcast_addr(input1, input2), expected);
     }
 
     #[rstest]
-    #[case(Ipv4Addr::new(1, 2, 3, 255), 1, Ipv4Addr::new(1, 2, 3, 254))]
-    #[case(Ipv4Addr::new(1, 2, 3, 127), 1, Ipv4Addr::new(1, 2, 3, 126))]
-    #[case(Ipv4Addr::new(1, 255, 255, 255), 1, Ipv4Addr::new(1, 255, 255, 254))]
-    #[case(Ipv4Addr::new(10, 0, 0, 0), 0, Ipv4Addr::new(10, 0, 0, 0))]
+    #[case(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 255)), 1, IpAddr::V4(Ipv4Addr::new(1, 2, 3, 254)))]
+    #[case(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 127)), 1, IpAddr::V4(Ipv4Addr::new(1, 2, 3, 126)))]
+    #[case(IpAddr::V4(Ipv4Addr::new(1, 255, 255, 255)), 1, IpAddr::V4(Ipv4Addr::new(1, 255, 255, 254)))]
+    #[case(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 0, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)))]
     fn test_get_last_host_addr(
-        #[case] input1: Ipv4Addr,
-        #[case] input2: u64,
-        #[case] expected: Ipv4Addr,
+        #[case] input1: IpAddr,
+        #[case] input2: u128,
+        #[case] expected: IpAddr,
     ) {
         // Arrange / Act / Assert
         assert_eq!(get_last_host_addr(input1, input2), expected);
     }
 
     #[rstest]
-    #[case(1, (2_147_483_648, 2_147_483_646))]
-    #[case(24, (256, 254))]
-    #[case(32, (1, 0))]
-    fn test_get_host_values(#[case] input: u8, #[case] expected: (u64, u64)) {
-        assert_eq!(get_host_values(input), expected);
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 1, (2_147_483_648, 2_147_483_646))]
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 24, (256, 254))]
+    #[case(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 32, (1, 0))]
+    #[case(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 127, (2, 0))]
+    #[case(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 128, (1, 0))]
+    fn test_get_host_values(#[case] ip: IpAddr, #[case] cidr: u8, #[case] expected: (u128, u128)) {
+        assert_eq!(get_host_values(ip, cidr), expected);
     }
 }
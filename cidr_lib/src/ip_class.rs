@@ -0,0 +1,104 @@
+use std::{fmt::Display, net::Ipv4Addr};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum IpClass {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+impl Display for IpClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Classifies `ip` by the leading bits of its first octet, per historical IPv4
+/// classful addressing.
+pub fn classify(ip: Ipv4Addr) -> IpClass {
+    match ip.octets()[0] {
+        0..=127 => IpClass::A,
+        128..=191 => IpClass::B,
+        192..=223 => IpClass::C,
+        224..=239 => IpClass::D,
+        240..=255 => IpClass::E,
+    }
+}
+
+/// Special-purpose ranges an address may fall into (RFC 1918, RFC 5735, RFC 3927).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Scope {
+    pub private: bool,
+    pub loopback: bool,
+    pub link_local: bool,
+    pub limited_broadcast: bool,
+}
+
+impl Scope {
+    /// `true` unless `ip` falls in a private, loopback, link-local, or broadcast range.
+    pub fn is_publicly_routable(&self) -> bool {
+        !(self.private || self.loopback || self.link_local || self.limited_broadcast)
+    }
+}
+
+pub fn scope(ip: Ipv4Addr) -> Scope {
+    let octets = ip.octets();
+
+    Scope {
+        private: octets[0] == 10
+            || (octets[0] == 172 && (16..=31).contains(&octets[1]))
+            || (octets[0] == 192 && octets[1] == 168),
+        loopback: octets[0] == 127,
+        link_local: octets[0] == 169 && octets[1] == 254,
+        limited_broadcast: ip == Ipv4Addr::new(255, 255, 255, 255),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use std::net::Ipv4Addr;
+
+    use super::{classify, scope, IpClass};
+
+    #[rstest]
+    #[case(Ipv4Addr::new(10, 0, 0, 1), IpClass::A)]
+    #[case(Ipv4Addr::new(127, 0, 0, 1), IpClass::A)]
+    #[case(Ipv4Addr::new(128, 0, 0, 1), IpClass::B)]
+    #[case(Ipv4Addr::new(191, 255, 255, 255), IpClass::B)]
+    #[case(Ipv4Addr::new(192, 168, 1, 1), IpClass::C)]
+    #[case(Ipv4Addr::new(223, 255, 255, 255), IpClass::C)]
+    #[case(Ipv4Addr::new(224, 0, 0, 1), IpClass::D)]
+    #[case(Ipv4Addr::new(240, 0, 0, 1), IpClass::E)]
+    fn test_classify(#[case] ip: Ipv4Addr, #[case] expected: IpClass) {
+        assert_eq!(classify(ip), expected);
+    }
+
+    #[rstest]
+    #[case(Ipv4Addr::new(10, 1, 2, 3), true, false, false, false)]
+    #[case(Ipv4Addr::new(172, 16, 0, 1), true, false, false, false)]
+    #[case(Ipv4Addr::new(172, 32, 0, 1), false, false, false, false)]
+    #[case(Ipv4Addr::new(192, 168, 1, 1), true, false, false, false)]
+    #[case(Ipv4Addr::new(127, 0, 0, 1), false, true, false, false)]
+    #[case(Ipv4Addr::new(169, 254, 1, 1), false, false, true, false)]
+    #[case(Ipv4Addr::new(255, 255, 255, 255), false, false, false, true)]
+    #[case(Ipv4Addr::new(8, 8, 8, 8), false, false, false, false)]
+    fn test_scope(
+        #[case] ip: Ipv4Addr,
+        #[case] private: bool,
+        #[case] loopback: bool,
+        #[case] link_local: bool,
+        #[case] limited_broadcast: bool,
+    ) {
+        let result = scope(ip);
+        assert_eq!(result.private, private);
+        assert_eq!(result.loopback, loopback);
+        assert_eq!(result.link_local, link_local);
+        assert_eq!(result.limited_broadcast, limited_broadcast);
+    }
+}
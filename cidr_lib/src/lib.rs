@@ -6,6 +6,7 @@
 pub mod cidr_info;
 pub mod error;
 pub mod helpers;
+pub mod ip_class;
 
 #[cfg(feature = "from_str")]
 pub mod from_str;